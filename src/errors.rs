@@ -0,0 +1,49 @@
+//! `errors` module contains the [`Error`] struct which is used across the `sanitation` crate to simplify error handling by transforming specific errors from known crates into [`Error`].
+
+use crate::to_hex;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use std::string::FromUtf8Error;
+#[cfg(not(feature = "std"))]
+use alloc::string::FromUtf8Error;
+
+/// `Error` represents errors occurring within the `sanitation` crate.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Error<'a> {
+    UnsafeString(&'a [u8], &'a [u8]),
+    InvalidUtf8(FromUtf8Error, &'a [u8], &'a [(usize, usize)], &'a [u8], &'a [u8]),
+    ParseError(String),
+    InteriorNul(usize),
+}
+
+impl core::fmt::Display for Error<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Error::UnsafeString(s, h) => write!(f, "unsafe conversion of byte-sequence {:#?} to string: contains garbage {:#?}", to_hex(s), to_hex(h)),
+            Error::InvalidUtf8(e, g, p, _r_, _s_) => {
+                let facets = p.iter().map(|(b, e)| format!("{}-{}", b, e)).collect::<Vec<String>>().join(", ");
+                write!(f, "unsafe byte array conversion to string `{}': {} at locations {{{}}}", e, to_hex(g), facets)
+            },
+            Error::ParseError(message) => write!(f, "{}", message),
+            Error::InteriorNul(position) => write!(f, "interior NUL byte found at offset {} while converting to a CString", position),
+        }
+    }
+}
+
+impl core::error::Error for Error<'_> {}
+
+#[cfg(feature = "std")]
+impl Into<std::io::Error> for Error<'_> {
+    fn into(self) -> std::io::Error {
+        std::io::Error::new(std::io::ErrorKind::InvalidData, format!("{}", self))
+    }
+}
+
+impl From<core::num::ParseIntError> for Error<'_> {
+    fn from(e: core::num::ParseIntError) -> Error<'static> {
+        Error::ParseError(e.to_string())
+    }
+}
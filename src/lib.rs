@@ -1,17 +1,47 @@
 //! sanitation is a tool for developing memory-safe programs while
 //! detecting and capturing possibly malicious bytes.
+//!
+//! By default this crate links against `std`. Disabling the default `std`
+//! feature and enabling `alloc` instead builds `sanitation` for `no_std`
+//! targets (embedded, SGX enclaves, and other bare-metal contexts) that
+//! still have a heap allocator, i.e. everywhere untrusted byte streams -
+//! this crate's whole reason to exist - show up. APIs that inherently need
+//! an operating system, such as [`SString::from_io_read`],
+//! [`SStringReader`] and the [`std::ffi::OsStr`]/[`std::ffi::OsString`]
+//! conversions, are only available under the `std` feature.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+#[cfg(all(test, not(feature = "std")))]
+extern crate std;
 
 pub mod errors;
 pub mod sboolean;
+pub mod scstring;
 pub mod sstring;
 pub mod traits;
 
 pub use errors::*;
 pub use sboolean::*;
+pub use scstring::*;
 pub use sstring::*;
 pub use traits::*;
 
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+#[cfg(all(test, not(feature = "std")))]
+use alloc::vec;
+
 /// Converts array of bytes into hexadecimal [`String`] representation.
+///
+/// This function cannot fail - it has no error path at all - and every byte
+/// is formatted regardless of its value, so there is no candidate byte for
+/// `memchr` to search for here, unlike
+/// [`SString::nul_positions`](crate::SString::nul_positions).
 pub fn to_hex(bytes: &[u8]) -> String {
     let mut to_hex = String::new();
     for byte in bytes {
@@ -0,0 +1,132 @@
+//! SCString (portmanteau of "Sanitation C String") is the FFI-oriented
+//! counterpart to [`SString`](crate::SString): a nul-terminated byte buffer
+//! meant for handing sanitized data across to C APIs that expect
+//! nul-terminated UTF-8.
+//!
+//! Unlike [`SString`](crate::SString), which elides invalid UTF-8 bytes into
+//! a garbage buffer, [`SCString`] treats an *interior* nul byte - one that
+//! is not the single trailing terminator - as the meaningful signal: it is
+//! a classic truncation/injection vector when data crosses the Rust/C
+//! boundary, so it is recorded as garbage rather than silently accepted or
+//! rejected outright.
+
+use crate::errors::Error;
+use alloc::format;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::ffi::CStr;
+use memchr::memchr_iter;
+
+/// `SCString` wraps a nul-terminated, valid-UTF-8 byte buffer, flagging any
+/// interior nul byte (i.e. one before the trailing terminator) as garbage.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SCString {
+    bytes: Vec<u8>,
+    garbage: Vec<usize>,
+}
+
+impl SCString {
+    /// `SCString::from_utf8_with_nul` builds an [`SCString`] from `bytes`,
+    /// verifying that it (1) ends in exactly one trailing `0x00`, (2) is
+    /// valid UTF-8 save for that terminator, and (3) recording the offset
+    /// of every interior `0x00` byte as garbage.
+    pub fn from_utf8_with_nul(bytes: &[u8]) -> Result<SCString, Error<'static>> {
+        if bytes.last() != Some(&0) {
+            return Err(Error::ParseError(
+                "byte slice is not nul-terminated".to_string(),
+            ));
+        }
+
+        let body = &bytes[..bytes.len() - 1];
+        if let Err(e) = core::str::from_utf8(body) {
+            return Err(Error::ParseError(format!(
+                "invalid UTF-8 at byte {}: {}",
+                e.valid_up_to(),
+                e
+            )));
+        }
+
+        let garbage = memchr_iter(0, body).collect();
+
+        Ok(SCString {
+            bytes: bytes.to_vec(),
+            garbage,
+        })
+    }
+
+    /// `SCString::from_utf8` appends the trailing nul terminator itself
+    /// before delegating to [`SCString::from_utf8_with_nul`].
+    pub fn from_utf8(body: &str) -> Result<SCString, Error<'static>> {
+        let mut bytes = Vec::with_capacity(body.len() + 1);
+        bytes.extend_from_slice(body.as_bytes());
+        bytes.push(0);
+        SCString::from_utf8_with_nul(&bytes)
+    }
+
+    /// `SCString::as_c_str` borrows this [`SCString`] as a [`CStr`] without
+    /// reallocating. Returns [`None`] when an interior nul was captured as
+    /// garbage, since such a buffer does not form a single valid C string.
+    pub fn as_c_str(&self) -> Option<&CStr> {
+        if self.has_garbage() {
+            return None;
+        }
+        CStr::from_bytes_with_nul(&self.bytes).ok()
+    }
+
+    /// `SCString::garbage` returns the offsets of every interior nul byte
+    /// captured while building this [`SCString`].
+    pub fn garbage(&self) -> Vec<usize> {
+        self.garbage.clone()
+    }
+
+    /// `SCString::has_garbage` returns `true` if one or more interior nul
+    /// bytes were captured while building this [`SCString`].
+    pub fn has_garbage(&self) -> bool {
+        !self.garbage.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod scstring_tests {
+    use super::SCString;
+    use crate::Error;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[test]
+    pub fn test_scstring_from_utf8_with_nul() {
+        let scstring = SCString::from_utf8_with_nul(b"hello\0").expect("valid");
+        assert_eq!(scstring.garbage(), Vec::<usize>::new());
+        assert_eq!(scstring.as_c_str().unwrap().to_bytes(), b"hello");
+    }
+
+    #[test]
+    pub fn test_scstring_from_utf8() {
+        let scstring = SCString::from_utf8("hello").expect("valid");
+        assert_eq!(scstring.as_c_str().unwrap().to_bytes(), b"hello");
+    }
+
+    #[test]
+    pub fn test_scstring_requires_trailing_nul() {
+        assert!(matches!(
+            SCString::from_utf8_with_nul(b"hello"),
+            Err(Error::ParseError(_))
+        ));
+    }
+
+    #[test]
+    pub fn test_scstring_captures_interior_nul_as_garbage() {
+        let scstring = SCString::from_utf8_with_nul(b"he\0lo\0").expect("valid utf8");
+        assert_eq!(scstring.garbage(), vec![2]);
+        assert!(scstring.has_garbage());
+        assert!(scstring.as_c_str().is_none());
+    }
+
+    #[test]
+    pub fn test_scstring_rejects_invalid_utf8() {
+        assert!(matches!(
+            SCString::from_utf8_with_nul(&[0xFF, 0x00]),
+            Err(Error::ParseError(_))
+        ));
+    }
+}
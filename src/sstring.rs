@@ -61,11 +61,54 @@
 //! ```
 
 use crate::errors::Error;
-use std::borrow::Cow;
+use alloc::borrow::Cow;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+use core::str::{from_utf8, Utf8Error};
+use memchr::memchr_iter;
+
+#[cfg(feature = "std")]
 use std::ffi::{OsStr, OsString};
-use std::fmt;
+#[cfg(feature = "std")]
 use std::io::Read;
-use std::str::{from_utf8, Utf8Error};
+
+#[cfg(feature = "std")]
+use std::ffi::{CStr, CString};
+#[cfg(not(feature = "std"))]
+use alloc::ffi::{CString};
+#[cfg(not(feature = "std"))]
+use core::ffi::CStr;
+
+/// `MatchRegion` classifies where a [`Match`] found by [`SString::find_bytes`]
+/// landed relative to the captured garbage spans.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatchRegion {
+    /// The match fell entirely within a valid UTF-8 chunk.
+    Valid,
+    /// The match fell entirely within a single captured garbage span.
+    Garbage,
+    /// The match overlapped both a garbage span and surrounding bytes.
+    Straddling,
+}
+
+/// `Match` reports a single occurrence of a needle found by
+/// [`SString::find_bytes`] and its variants.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Match {
+    pub offset: usize,
+    pub length: usize,
+    pub region: MatchRegion,
+}
+
+/// `Endianness` selects the byte order [`SString::from_utf16_bytes`] uses to
+/// group raw bytes into `u16` code units before decoding them as UTF-16.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Endianness {
+    Big,
+    Little,
+}
 /// `SString` is a struct which keeps an internal state comprised of data fed into it:
 ///
 /// - A [`Vec`] of sequential bytes
@@ -81,9 +124,10 @@ pub struct SString {
 }
 impl SString {
     /// Creates new instance of [`SString`] from implementations of [`std::io::Read`] and [`std::fmt::Debug`]
+    #[cfg(feature = "std")]
     pub fn from_io_read(mut s: impl Read + ::std::fmt::Debug) -> ::std::io::Result<SString> {
         let mut raw: Vec<u8> = Vec::new();
-        s.read(&mut raw)?;
+        s.read_to_end(&mut raw)?;
         Ok(SString::new(&raw))
     }
 
@@ -112,6 +156,7 @@ impl SString {
     ) {
         let mut input = raw.clone();
         self.i.extend(&input);
+        let mut consumed = self.i.len() - input.len();
         loop {
             match from_utf8(&input) {
                 Ok(valid) => {
@@ -128,12 +173,15 @@ impl SString {
                         self.s.extend(valid);
 
                         if let Some(error_len) = error.error_len() {
-                            let valid_start = error.valid_up_to();
-                            let valid_end = valid_start + error_len;
+                            let local_start = error.valid_up_to();
+                            let local_end = local_start + error_len;
+                            let valid_start = consumed + local_start;
+                            let valid_end = consumed + local_end;
 
-                            self.p.insert(0, (valid_start, valid_end));
-                            let bytes = &mut input[valid_start..valid_end].to_vec();
+                            self.p.push((valid_start, valid_end));
+                            let bytes = &mut input[local_start..local_end].to_vec();
                             self.g.extend(&mut bytes.iter());
+                            consumed = valid_end;
                             input = after_valid[error_len..].to_vec();
                         } else {
                             break;
@@ -191,6 +239,98 @@ impl SString {
         String::from_utf8(self.s.clone()).expect("valid UTF-8 bytes")
     }
 
+    /// `SString::utf8_runs` walks `self.i` exactly once, advancing past
+    /// each maximal valid UTF-8 run via [`Utf8Error::valid_up_to`] and
+    /// [`Utf8Error::error_len`] rather than inspecting bytes one at a time,
+    /// and yields that run's text together with the `(start, end)` byte
+    /// span - within the original input - of any garbage immediately
+    /// following it (`None` once the final, garbage-free run is reached).
+    /// [`SString::lossy`], [`SString::lossy_offsets`],
+    /// [`SString::safe_lossy`] and [`SString::find_spanning`]'s private
+    /// [`SString::valid_chunk_boundaries_in_safe`] are all built on top of
+    /// this single pass instead of each re-walking `self.i` through
+    /// [`from_utf8`]'s error reporting on their own.
+    fn utf8_runs(&self) -> Vec<(&str, Option<(usize, usize)>)> {
+        let mut runs = Vec::new();
+        let mut input = &self.i[..];
+        let mut consumed = 0usize;
+        loop {
+            match from_utf8(input) {
+                Ok(valid) => {
+                    runs.push((valid, None));
+                    break;
+                }
+                Err(error) => {
+                    let valid_up_to = error.valid_up_to();
+                    let valid = from_utf8(&input[..valid_up_to]).expect("valid UTF-8 bytes");
+                    match error.error_len() {
+                        Some(error_len) => {
+                            let start = consumed + valid_up_to;
+                            let end = start + error_len;
+                            runs.push((valid, Some((start, end))));
+                            consumed = end;
+                            input = &input[valid_up_to + error_len..];
+                        }
+                        None => {
+                            let start = consumed + valid_up_to;
+                            let end = consumed + input.len();
+                            runs.push((valid, Some((start, end))));
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+        runs
+    }
+
+    /// `SString::lossy` returns a valid [`String`] that mirrors
+    /// [`String::from_utf8_lossy`] while keeping the positional information
+    /// [`SString::unchecked_safe`] throws away: every captured garbage span
+    /// is rendered as a single U+FFFD replacement character at the offset
+    /// where it originally occurred, instead of being silently elided.
+    pub fn lossy(&self) -> String {
+        let mut result = String::new();
+        for (valid, garbage) in self.utf8_runs() {
+            result.push_str(valid);
+            if garbage.is_some() {
+                result.push('\u{FFFD}');
+            }
+        }
+        result
+    }
+
+    /// `SString::lossy_offsets` returns the `(start, end)` byte ranges, in
+    /// the original input, of every garbage run that [`SString::lossy`]
+    /// replaced with a single U+FFFD, in the order they appear in the
+    /// rendered string. This lets callers report exactly where in a
+    /// protocol frame an anomaly was found even after the lossy rendering
+    /// has collapsed it down to a single replacement character.
+    pub fn lossy_offsets(&self) -> Vec<(usize, usize)> {
+        self.utf8_runs()
+            .into_iter()
+            .filter_map(|(_, garbage)| garbage)
+            .collect()
+    }
+
+    /// `SString::safe_lossy` is a convenience combining [`SString::lossy`]
+    /// and [`SString::lossy_offsets`], returning both the rendered string
+    /// and the input byte ranges each of its U+FFFD characters stands in
+    /// for, computed from a single walk over `self.i` via
+    /// [`SString::utf8_runs`] rather than scanning it twice.
+    pub fn safe_lossy(&self) -> (String, Vec<(usize, usize)>) {
+        let mut result = String::new();
+        let mut offsets = Vec::new();
+        for (valid, garbage) in self.utf8_runs() {
+            result.push_str(valid);
+            if let Some(range) = garbage {
+                result.push('\u{FFFD}');
+                offsets.push(range);
+            }
+        }
+        (result, offsets)
+    }
+
     /// `SString::garbage_len` returns the length of the contiguous
     /// non-valid UTF-8 bytes within its associated SString instance.
     pub fn garbage_len(&self) -> usize {
@@ -203,6 +343,14 @@ impl SString {
     ///
     /// This method might be perceived as having an opposing function
     /// to that of the [`SString::safe_vec`] method.
+    ///
+    /// This, like [`SString::valid_utf8_chunk_boundaries`], is a plain
+    /// clone of state `g`/`p` already populated by [`SString::extend_vec`];
+    /// there is no per-call scan here for `memchr` to accelerate. The
+    /// scanning work `memchr` does help with lives in
+    /// [`SString::nul_positions`] and [`SString::interior_nul_positions`],
+    /// and the jump-over-whole-valid-UTF-8-runs work lives in
+    /// [`SString::utf8_runs`].
     pub fn garbage(&self) -> Vec<u8> {
         self.g.clone()
     }
@@ -245,6 +393,7 @@ impl SString {
 
     /// `SString::toosstr` returns a [`OsString`] comprised of all
     /// contiguous valid UTF-8 bytes fed into an instance of SString.
+    #[cfg(feature = "std")]
     pub fn toosstr(&self) -> OsString {
         let mut osstr = OsString::new();
         osstr.push(self.unchecked_safe());
@@ -252,10 +401,335 @@ impl SString {
     }
 
     /// `SString::valid_utf8_chunk_boundaries` returns a [`Vec<(usize, usize)>`] comprised of all
-    /// the `(start, end)` boundaries of valid UTF-8 chunks of bytes
+    /// the `(start, end)` boundaries of valid UTF-8 chunks of bytes.
+    ///
+    /// See the note on [`SString::garbage`]: this is a plain clone of
+    /// already-populated state, not a scan.
     pub fn valid_utf8_chunk_boundaries(&self) -> Vec<(usize, usize)> {
         self.p.clone()
     }
+
+    /// Creates a new instance of [`SString`] by decoding a stream of UTF-16 code
+    /// units. Every successfully decoded [`char`] is appended to the valid
+    /// buffer; an unpaired surrogate is instead appended to the garbage buffer
+    /// and has its `(start, end)` byte span recorded, mirroring how
+    /// [`SString::extend_vec`] handles invalid UTF-8 byte sequences.
+    pub fn from_utf16(units: &[u16]) -> SString {
+        let mut sstring = SString::empty();
+        sstring.extend_utf16(units);
+        sstring
+    }
+
+    /// Creates a new instance of [`SString`] by grouping `raw` into `u16` code
+    /// units according to `endianness` and decoding them as UTF-16 (see
+    /// [`SString::from_utf16`]). A trailing byte that cannot form a full code
+    /// unit is itself recorded as garbage.
+    pub fn from_utf16_bytes(raw: &[u8], endianness: Endianness) -> SString {
+        let mut sstring = SString::empty();
+        sstring.extend_utf16_bytes(raw, endianness);
+        sstring
+    }
+
+    /// `SString::extend_utf16_bytes` groups `raw` into `u16` code units
+    /// according to `endianness` and feeds them into this [`SString`] via
+    /// [`SString::extend_utf16`], just as [`SString::from_utf16_bytes`] does
+    /// for a fresh instance. A trailing byte that cannot form a full code
+    /// unit is recorded as garbage.
+    pub fn extend_utf16_bytes(&mut self, raw: &[u8], endianness: Endianness) {
+        let mut chunks = raw.chunks_exact(2);
+        let units: Vec<u16> = chunks
+            .by_ref()
+            .map(|pair| match endianness {
+                Endianness::Big => u16::from_be_bytes([pair[0], pair[1]]),
+                Endianness::Little => u16::from_le_bytes([pair[0], pair[1]]),
+            })
+            .collect();
+        self.extend_utf16_endian(&units, endianness);
+
+        let remainder = chunks.remainder();
+        if !remainder.is_empty() {
+            let start = self.i.len();
+            self.i.extend(remainder);
+            self.g.extend(remainder);
+            self.p.push((start, start + remainder.len()));
+        }
+    }
+
+    /// `SString::find_bytes` scans the original byte stream for every
+    /// occurrence of any of `needles`, the way a caller registering a set of
+    /// known shellcode/NOP-sled signatures would, and reports each hit's
+    /// offset, length, and whether it fell in a valid chunk, a garbage
+    /// chunk, or straddled the boundary between the two (using the chunk
+    /// boundary table already maintained in `p`).
+    pub fn find_bytes(&self, needles: &[&[u8]]) -> Vec<Match> {
+        let mut matches = Vec::new();
+        for needle in needles {
+            if needle.is_empty() || needle.len() > self.i.len() {
+                continue;
+            }
+            for (offset, window) in self.i.windows(needle.len()).enumerate() {
+                if window == *needle {
+                    matches.push(Match {
+                        offset,
+                        length: needle.len(),
+                        region: self.classify_match_region(offset, needle.len()),
+                    });
+                }
+            }
+        }
+        matches.sort_by_key(|m| m.offset);
+        matches
+    }
+
+    /// `SString::find_in_garbage` behaves like [`SString::find_bytes`] but
+    /// only returns matches fully contained within a captured garbage span.
+    pub fn find_in_garbage(&self, needles: &[&[u8]]) -> Vec<Match> {
+        self.find_bytes(needles)
+            .into_iter()
+            .filter(|m| m.region == MatchRegion::Garbage)
+            .collect()
+    }
+
+    /// `SString::find_in_safe` behaves like [`SString::find_bytes`] but only
+    /// returns matches fully contained within a valid UTF-8 chunk.
+    pub fn find_in_safe(&self, needles: &[&[u8]]) -> Vec<Match> {
+        self.find_bytes(needles)
+            .into_iter()
+            .filter(|m| m.region == MatchRegion::Valid)
+            .collect()
+    }
+
+    fn classify_match_region(&self, offset: usize, length: usize) -> MatchRegion {
+        let end = offset + length;
+        if self.p.iter().any(|&(start, g_end)| offset >= start && end <= g_end) {
+            return MatchRegion::Garbage;
+        }
+        if self
+            .p
+            .iter()
+            .any(|&(start, g_end)| offset < g_end && end > start)
+        {
+            return MatchRegion::Straddling;
+        }
+        MatchRegion::Valid
+    }
+
+    /// `SString::find` returns the byte index of the first occurrence of
+    /// `pat` within the reconstructed valid text (see
+    /// [`SString::unchecked_safe`]), or [`None`] if it does not occur.
+    pub fn find(&self, pat: &str) -> Option<usize> {
+        self.unchecked_safe().find(pat)
+    }
+
+    /// `SString::contains` returns `true` if `pat` occurs anywhere within the
+    /// reconstructed valid text.
+    pub fn contains(&self, pat: &str) -> bool {
+        self.unchecked_safe().contains(pat)
+    }
+
+    /// `SString::match_indices` returns every `(byte index, matched text)`
+    /// pair of `pat` found within the reconstructed valid text.
+    pub fn match_indices(&self, pat: &str) -> Vec<(usize, String)> {
+        self.unchecked_safe()
+            .match_indices(pat)
+            .map(|(index, matched)| (index, matched.to_string()))
+            .collect()
+    }
+
+    /// `SString::find_spanning` behaves like [`SString::find`], but the
+    /// returned `bool` indicates whether the match would have straddled one
+    /// or more garbage spans that were elided from the original byte stream.
+    /// A keyword that only appears because invalid bytes were removed
+    /// between two otherwise-unrelated valid runs is suspicious: callers can
+    /// use this to distinguish "token present in clean data" from "token
+    /// only present after garbage removal".
+    pub fn find_spanning(&self, pat: &str) -> Option<(usize, bool)> {
+        let index = self.find(pat)?;
+        let end = index + pat.len();
+        let straddles = self
+            .valid_chunk_boundaries_in_safe()
+            .into_iter()
+            .any(|boundary| boundary > index && boundary < end);
+        Some((index, straddles))
+    }
+
+    /// Returns the offsets, within the string returned by
+    /// [`SString::unchecked_safe`], at which a garbage span was elided while
+    /// concatenating valid UTF-8 chunks together. Built on
+    /// [`SString::utf8_runs`]'s single walk over `self.i`, accumulating
+    /// each run's length - rather than `self.i`'s own byte offsets - since
+    /// this reports positions in the garbage-free concatenated string.
+    fn valid_chunk_boundaries_in_safe(&self) -> Vec<usize> {
+        let mut boundaries = Vec::new();
+        let mut consumed = 0usize;
+        for (valid, garbage) in self.utf8_runs() {
+            consumed += valid.len();
+            if garbage.is_some() {
+                boundaries.push(consumed);
+            }
+        }
+        boundaries
+    }
+
+    /// `SString::normalize` interprets this SString's valid UTF-8 bytes the
+    /// way config/quoted-string formats do: a single layer of surrounding
+    /// double quotes is stripped, and backslash escapes within are
+    /// unescaped (`\"` -> `"`, `\\` -> `\`, plus `\n` and `\t`). When no
+    /// escape needs expanding - including when only the surrounding quotes
+    /// are stripped, which just narrows the borrowed slice - the returned
+    /// [`Cow`] is a zero-allocation [`Cow::Borrowed`]; a [`Cow::Owned`] is
+    /// only allocated once an escape must be expanded.
+    ///
+    /// This assumes the input contains an even number of unescaped quotes.
+    /// A dangling unescaped quote or a trailing lone backslash is not valid
+    /// quoted input; since this method borrows `&self` it has no way to
+    /// feed such bytes into this [`SString`]'s own garbage buffer the way
+    /// [`SString::extend_vec`] does, so instead the second element of the
+    /// returned tuple reports `true` in that case. The offending bytes
+    /// themselves are left untouched in the returned value rather than
+    /// being stripped or causing a panic.
+    pub fn normalize(&self) -> (Cow<[u8]>, bool) {
+        let bytes = &self.s[..];
+        let body = if bytes.len() >= 2 && bytes[0] == b'"' && bytes[bytes.len() - 1] == b'"' {
+            &bytes[1..bytes.len() - 1]
+        } else {
+            bytes
+        };
+
+        let has_escape = body
+            .windows(2)
+            .any(|pair| pair[0] == b'\\' && matches!(pair[1], b'"' | b'\\' | b'n' | b't'));
+        if !has_escape {
+            let unescaped_quotes = body.iter().filter(|&&byte| byte == b'"').count();
+            let dangling_backslash = body.last() == Some(&b'\\');
+            return (
+                Cow::Borrowed(body),
+                dangling_backslash || !unescaped_quotes.is_multiple_of(2),
+            );
+        }
+
+        let mut out = Vec::with_capacity(body.len());
+        let mut unescaped_quotes = 0usize;
+        let mut dangling_backslash = false;
+        let mut i = 0;
+        while i < body.len() {
+            let byte = body[i];
+            if byte == b'\\' {
+                match body.get(i + 1) {
+                    Some(b'"') => {
+                        out.push(b'"');
+                        i += 2;
+                    }
+                    Some(b'\\') => {
+                        out.push(b'\\');
+                        i += 2;
+                    }
+                    Some(b'n') => {
+                        out.push(b'\n');
+                        i += 2;
+                    }
+                    Some(b't') => {
+                        out.push(b'\t');
+                        i += 2;
+                    }
+                    None => {
+                        dangling_backslash = true;
+                        out.push(byte);
+                        i += 1;
+                    }
+                    _ => {
+                        out.push(byte);
+                        i += 1;
+                    }
+                }
+            } else {
+                if byte == b'"' {
+                    unescaped_quotes += 1;
+                }
+                out.push(byte);
+                i += 1;
+            }
+        }
+        (Cow::Owned(out), dangling_backslash || !unescaped_quotes.is_multiple_of(2))
+    }
+
+    /// `SString::nul_positions` returns the byte offsets of every `0x00`
+    /// byte found anywhere in the original stream fed into this SString,
+    /// valid or not. Unlike [`SString::interior_nul_positions`], which only
+    /// looks at the bytes that made it into the valid buffer, this scans
+    /// every byte so callers can flag suspicious embedded NULs even when
+    /// they intend to keep them around rather than build a [`CString`].
+    pub fn nul_positions(&self) -> Vec<usize> {
+        memchr_iter(0, &self.i).collect()
+    }
+
+    /// `SString::to_cstring` builds a [`CString`] out of this SString's
+    /// valid UTF-8 bytes. Given the crate's focus on binary protocols,
+    /// interior NUL bytes are treated as a meaningful signal rather than
+    /// ordinary valid data: if one is found, this method returns
+    /// [`Error::InteriorNul`] carrying its byte offset instead of panicking,
+    /// matching the no-panic contract of [`CString::new`].
+    pub fn to_cstring(&self) -> Result<CString, Error> {
+        CString::new(self.s.clone()).map_err(|e| Error::InteriorNul(e.nul_position()))
+    }
+
+    /// `SString::from_cstr` creates a new instance of [`SString`] from the
+    /// bytes of a [`CStr`], excluding its trailing NUL terminator.
+    pub fn from_cstr(cstr: &CStr) -> SString {
+        SString::new(cstr.to_bytes())
+    }
+
+    /// `SString::interior_nul_positions` returns the byte offsets of every
+    /// `0x00` byte found among this SString's valid UTF-8 bytes, letting
+    /// callers scanning a stream flag NUL-based covert channels without
+    /// having to convert to a [`CString`] first.
+    pub fn interior_nul_positions(&self) -> Vec<usize> {
+        memchr_iter(0, &self.s).collect()
+    }
+
+    /// Decodes `units` as UTF-16 via [`char::decode_utf16`], extending this
+    /// [`SString`]'s valid and garbage buffers in place. Each decoded `char`
+    /// is re-encoded as UTF-8 and appended to the valid buffer; a
+    /// [`std::char::DecodeUtf16Error`] instead contributes its
+    /// [`unpaired_surrogate()`](std::char::DecodeUtf16Error::unpaired_surrogate)
+    /// bytes to the garbage buffer, with the matching span recorded in the
+    /// chunk-boundary table. `units` has no associated byte stream of its
+    /// own, so each `u16` is serialized big-endian into `self.i`.
+    pub fn extend_utf16(&mut self, units: &[u16]) {
+        self.extend_utf16_endian(units, Endianness::Big)
+    }
+
+    /// Shared by [`SString::extend_utf16`], which has no original byte
+    /// stream to preserve and so serializes big-endian by convention, and
+    /// [`SString::extend_utf16_bytes`], which must record `self.i` (and any
+    /// garbage span within it) using the same `endianness` the caller's raw
+    /// bytes were actually in, rather than silently transposing them.
+    fn extend_utf16_endian(&mut self, units: &[u16], endianness: Endianness) {
+        let to_bytes = |unit: u16| match endianness {
+            Endianness::Big => unit.to_be_bytes(),
+            Endianness::Little => unit.to_le_bytes(),
+        };
+
+        let mut offset = self.i.len();
+        for unit in units {
+            self.i.extend(&to_bytes(*unit));
+        }
+        for result in char::decode_utf16(units.iter().cloned()) {
+            match result {
+                Ok(c) => {
+                    let mut buf = [0u8; 4];
+                    self.s.extend(c.encode_utf8(&mut buf).as_bytes());
+                    offset += c.len_utf16() * 2;
+                }
+                Err(e) => {
+                    let bytes = to_bytes(e.unpaired_surrogate());
+                    self.p.push((offset, offset + 2));
+                    self.g.extend(&bytes);
+                    offset += 2;
+                }
+            }
+        }
+    }
 }
 
 impl Into<Cow<'static, str>> for SString {
@@ -270,6 +744,7 @@ impl<'s> Into<&'s str> for SString {
     }
 }
 
+#[cfg(feature = "std")]
 impl Into<OsString> for SString {
     fn into(self) -> OsString {
         self.toosstr()
@@ -291,6 +766,7 @@ impl From<Cow<'static, str>> for SString {
     }
 }
 
+#[cfg(feature = "std")]
 impl From<OsString> for SString {
     fn from(os: OsString) -> SString {
         SString::new(&os.into_encoded_bytes())
@@ -307,6 +783,7 @@ impl From<&str> for SString {
         SString::new(p.as_bytes())
     }
 }
+#[cfg(feature = "std")]
 impl From<&OsStr> for SString {
     fn from(p: &OsStr) -> SString {
         SString::new(p.as_encoded_bytes())
@@ -319,6 +796,18 @@ impl From<&[u8]> for SString {
     }
 }
 
+impl From<CString> for SString {
+    fn from(c: CString) -> SString {
+        SString::new(c.as_bytes())
+    }
+}
+
+impl From<&CStr> for SString {
+    fn from(c: &CStr) -> SString {
+        SString::from_cstr(c)
+    }
+}
+
 impl fmt::Display for SString {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.unchecked_safe())
@@ -413,9 +902,106 @@ impl Default for SString {
     }
 }
 
+/// `SStringReader` wraps an [`impl Read`](Read) and incrementally decodes it
+/// into an [`SString`], correctly holding back a trailing incomplete UTF-8
+/// sequence (up to three bytes) across `feed` calls instead of misclassifying
+/// it as garbage. This lets callers sanitize arbitrarily large socket streams
+/// without a multibyte scalar straddling a read boundary being torn apart.
+#[cfg(feature = "std")]
+pub struct SStringReader<R: Read> {
+    inner: R,
+    sstring: SString,
+    pending: Vec<u8>,
+}
+
+#[cfg(feature = "std")]
+impl<R: Read> SStringReader<R> {
+    /// Creates a new [`SStringReader`] wrapping `inner`.
+    pub fn new(inner: R) -> SStringReader<R> {
+        SStringReader {
+            inner,
+            sstring: SString::empty(),
+            pending: Vec::new(),
+        }
+    }
+
+    /// `SStringReader::feed` decodes `chunk`, prefixed with any incomplete
+    /// sequence held back from a previous call, appending whole valid runs
+    /// to the underlying [`SString`] and committing whole invalid runs to
+    /// garbage. A trailing incomplete multibyte sequence is held back rather
+    /// than discarded, to be re-decoded once more bytes arrive.
+    pub fn feed(&mut self, chunk: &[u8]) {
+        let mut input = std::mem::take(&mut self.pending);
+        input.extend_from_slice(chunk);
+
+        let mut remaining = &input[..];
+        loop {
+            match from_utf8(remaining) {
+                Ok(valid) => {
+                    self.sstring.i.extend(remaining);
+                    self.sstring.s.extend(valid.as_bytes());
+                    break;
+                }
+                Err(error) => {
+                    let valid_up_to = error.valid_up_to();
+                    let (valid, rest) = remaining.split_at(valid_up_to);
+                    self.sstring.i.extend(valid);
+                    self.sstring.s.extend(valid);
+
+                    match error.error_len() {
+                        Some(error_len) => {
+                            let (garbage, rest) = rest.split_at(error_len);
+                            let start = self.sstring.i.len();
+                            self.sstring.i.extend(garbage);
+                            self.sstring.g.extend(garbage);
+                            self.sstring.p.push((start, start + error_len));
+                            remaining = rest;
+                        }
+                        None => {
+                            self.pending = rest.to_vec();
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Reads `inner` to completion, feeding every chunk through
+    /// [`SStringReader::feed`].
+    pub fn read_to_end(&mut self) -> std::io::Result<()> {
+        let mut buf = [0u8; 4096];
+        loop {
+            let read = self.inner.read(&mut buf)?;
+            if read == 0 {
+                break;
+            }
+            self.feed(&buf[..read]);
+        }
+        Ok(())
+    }
+
+    /// Consumes this [`SStringReader`], committing any still-incomplete
+    /// trailing bytes to garbage, and returns the decoded [`SString`].
+    pub fn finish(mut self) -> SString {
+        if !self.pending.is_empty() {
+            let start = self.sstring.i.len();
+            self.sstring.i.extend(&self.pending);
+            self.sstring.g.extend(&self.pending);
+            self.sstring.p.push((start, start + self.pending.len()));
+        }
+        self.sstring
+    }
+}
+
 #[cfg(test)]
 mod sstring_tests {
     use crate::{Error, SString};
+    use alloc::borrow::Cow;
+    use alloc::format;
+    use alloc::string::String;
+    use alloc::vec;
+    use alloc::vec::Vec;
 
     #[test]
     pub fn test_sstring_from_invalid_utf8() {
@@ -478,4 +1064,255 @@ mod sstring_tests {
         let sstring = vecs.iter().map(|bytes| bytes.clone()).collect::<SString>();
         assert_eq!(sstring.unchecked_safe(), "~?~");
     }
+
+    #[test]
+    pub fn test_sstring_lossy() {
+        let bytes: Vec<u8> = vec![0x61, 0x62, 0xFF, 0x63, 0x64];
+        let sstring = SString::new(&bytes);
+        assert_eq!(sstring.unchecked_safe(), "abcd");
+        assert_eq!(sstring.lossy(), "ab\u{FFFD}cd");
+
+        let bytes: Vec<u8> = vec![0x61, 0x62, 0xFF];
+        let sstring = SString::new(&bytes);
+        assert_eq!(sstring.lossy(), "ab\u{FFFD}");
+    }
+
+    #[test]
+    pub fn test_sstring_lossy_offsets() {
+        let bytes: Vec<u8> = vec![0x61, 0x62, 0xFF, 0x63, 0x64, 0xFE, 0xFE];
+        let sstring = SString::new(&bytes);
+        assert_eq!(sstring.lossy(), "ab\u{FFFD}cd\u{FFFD}\u{FFFD}");
+        assert_eq!(sstring.lossy_offsets(), vec![(2, 3), (5, 6), (6, 7)]);
+        assert_eq!(
+            sstring.safe_lossy(),
+            (sstring.lossy(), sstring.lossy_offsets())
+        );
+    }
+
+    #[test]
+    pub fn test_sstring_find_and_contains() {
+        let sstring = SString::new(b"hello world");
+        assert_eq!(sstring.find("world"), Some(6));
+        assert!(sstring.contains("lo wo"));
+        assert_eq!(
+            sstring.match_indices("o"),
+            vec![(4, format!("o")), (7, format!("o"))]
+        );
+    }
+
+    #[test]
+    pub fn test_sstring_find_bytes() {
+        // "AA" + a single 0x90 garbage byte + "AA"
+        let bytes: Vec<u8> = vec![0x41, 0x41, 0x90, 0x41, 0x41];
+        let sstring = SString::new(&bytes);
+
+        let matches = sstring.find_bytes(&[b"AA", &[0x90], &[0x41, 0x90]]);
+        assert_eq!(
+            matches,
+            vec![
+                crate::Match { offset: 0, length: 2, region: crate::MatchRegion::Valid },
+                crate::Match { offset: 1, length: 2, region: crate::MatchRegion::Straddling },
+                crate::Match { offset: 2, length: 1, region: crate::MatchRegion::Garbage },
+                crate::Match { offset: 3, length: 2, region: crate::MatchRegion::Valid },
+            ]
+        );
+
+        assert_eq!(sstring.find_in_safe(&[b"AA"]).len(), 2);
+        assert_eq!(sstring.find_in_garbage(&[&[0x90]]).len(), 1);
+    }
+
+    #[test]
+    pub fn test_sstring_find_bytes_multiple_garbage_spans() {
+        // "ab" + 0xFF (garbage) + "cd" + 0xFE, 0xFE (two more garbage bytes)
+        let bytes: Vec<u8> = vec![0x61, 0x62, 0xFF, 0x63, 0x64, 0xFE, 0xFE];
+        let sstring = SString::new(&bytes);
+
+        assert_eq!(
+            sstring.valid_utf8_chunk_boundaries(),
+            vec![(2, 3), (5, 6), (6, 7)]
+        );
+
+        let matches = sstring.find_in_garbage(&[&[0xFE]]);
+        assert_eq!(
+            matches,
+            vec![
+                crate::Match { offset: 5, length: 1, region: crate::MatchRegion::Garbage },
+                crate::Match { offset: 6, length: 1, region: crate::MatchRegion::Garbage },
+            ]
+        );
+    }
+
+    #[test]
+    pub fn test_sstring_find_spanning() {
+        // "fo" + 0xFF (garbage) + "o" -> unchecked_safe() == "foo", but "foo" only
+        // exists because the garbage byte between "fo" and "o" was elided.
+        let bytes: Vec<u8> = vec![0x66, 0x6F, 0xFF, 0x6F];
+        let sstring = SString::new(&bytes);
+        assert_eq!(sstring.unchecked_safe(), "foo");
+        assert_eq!(sstring.find_spanning("foo"), Some((0, true)));
+        assert_eq!(sstring.find_spanning("fo"), Some((0, false)));
+
+        let clean = SString::new(b"foo");
+        assert_eq!(clean.find_spanning("foo"), Some((0, false)));
+    }
+
+    #[test]
+    pub fn test_sstring_to_cstring() {
+        let sstring = SString::new(b"hello");
+        let cstring = sstring.to_cstring().expect("no interior nul");
+        assert_eq!(cstring.as_bytes(), b"hello");
+
+        let sstring = SString::new(b"he\x00llo");
+        assert_eq!(sstring.interior_nul_positions(), vec![2]);
+        assert_eq!(sstring.to_cstring(), Err(Error::InteriorNul(2)));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    pub fn test_sstring_reader_holds_back_split_sequence() {
+        use crate::SStringReader;
+
+        // "caf" + the two bytes of U+00E9 (é) split across two feeds.
+        let e_acute = "é".as_bytes().to_vec();
+        let mut reader = SStringReader::new(std::io::empty());
+        reader.feed(b"caf");
+        reader.feed(&e_acute[..1]);
+        reader.feed(&e_acute[1..]);
+        let sstring = reader.finish();
+
+        assert_eq!(sstring.unchecked_safe(), "café");
+        assert_eq!(sstring.garbage(), Vec::<u8>::new());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    pub fn test_sstring_reader_commits_incomplete_trailer_on_finish() {
+        use crate::SStringReader;
+
+        let e_acute = "é".as_bytes().to_vec();
+        let mut reader = SStringReader::new(std::io::empty());
+        reader.feed(b"caf");
+        reader.feed(&e_acute[..1]);
+        let sstring = reader.finish();
+
+        assert_eq!(sstring.unchecked_safe(), "caf");
+        assert_eq!(sstring.garbage(), e_acute[..1].to_vec());
+    }
+
+    #[test]
+    pub fn test_sstring_normalize() {
+        let plain = SString::new(b"hello");
+        assert_eq!(
+            plain.normalize(),
+            (Cow::Borrowed(b"hello".as_slice()), false)
+        );
+
+        let quoted = SString::new(b"\"hello\"");
+        assert_eq!(
+            quoted.normalize(),
+            (Cow::Borrowed(b"hello".as_slice()), false)
+        );
+
+        let escaped = SString::new(b"\"he\\\"llo\\n\\t\\\\\"");
+        assert_eq!(
+            escaped.normalize(),
+            (Cow::<[u8]>::Owned(b"he\"llo\n\t\\".to_vec()), false)
+        );
+
+        let dangling_quote = SString::new(b"\"hello");
+        assert_eq!(
+            dangling_quote.normalize(),
+            (Cow::Borrowed(b"\"hello".as_slice()), true)
+        );
+
+        let trailing_backslash = SString::new(b"hello\\");
+        assert_eq!(
+            trailing_backslash.normalize(),
+            (Cow::Borrowed(b"hello\\".as_slice()), true)
+        );
+    }
+
+    #[test]
+    pub fn test_sstring_nul_positions() {
+        let bytes: Vec<u8> = vec![0x61, 0x00, 0xFF, 0x00, 0x62];
+        let sstring = SString::new(&bytes);
+        assert_eq!(sstring.nul_positions(), vec![1, 3]);
+    }
+
+    #[test]
+    pub fn test_sstring_from_cstr() {
+        let cstring = std::ffi::CString::new("hello").unwrap();
+        let sstring = SString::from_cstr(&cstring);
+        assert_eq!(sstring.unchecked_safe(), "hello");
+
+        let sstring = SString::from(cstring);
+        assert_eq!(sstring.unchecked_safe(), "hello");
+    }
+
+    #[test]
+    pub fn test_sstring_from_utf16() {
+        // "Hi" followed by an unpaired high surrogate (0xD800)
+        let units: Vec<u16> = vec![0x0048, 0x0069, 0xD800];
+        let sstring = SString::from_utf16(&units);
+
+        assert_eq!(sstring.unchecked_safe(), "Hi");
+        assert_eq!(sstring.garbage(), vec![0xD8, 0x00]);
+        assert_eq!(sstring.valid_utf8_chunk_boundaries(), vec![(4, 6)]);
+    }
+
+    #[test]
+    pub fn test_sstring_from_utf16_bytes() {
+        let be_bytes: Vec<u8> = vec![0x00, 0x48, 0x00, 0x69];
+        let sstring = SString::from_utf16_bytes(&be_bytes, crate::Endianness::Big);
+        assert_eq!(sstring.unchecked_safe(), "Hi");
+
+        let le_bytes: Vec<u8> = vec![0x48, 0x00, 0x69, 0x00, 0xAB];
+        let sstring = SString::from_utf16_bytes(&le_bytes, crate::Endianness::Little);
+        assert_eq!(sstring.unchecked_safe(), "Hi");
+        assert_eq!(sstring.garbage(), vec![0xAB]);
+
+        // `self.i` must hold the exact bytes fed in, not a re-encoded copy:
+        // searching for the original little-endian bytes has to succeed.
+        assert_eq!(
+            sstring
+                .find_bytes(&[&[0x48, 0x00, 0x69, 0x00]])
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    pub fn test_sstring_extend_utf16_bytes_preserves_little_endian_garbage() {
+        // An unpaired high surrogate (0xD800) encoded little-endian.
+        let le_bytes: Vec<u8> = vec![0x41, 0x00, 0x00, 0xD8];
+        let sstring = SString::from_utf16_bytes(&le_bytes, crate::Endianness::Little);
+        assert_eq!(sstring.unchecked_safe(), "A");
+        assert_eq!(sstring.garbage(), vec![0x00, 0xD8]);
+    }
+
+    #[test]
+    pub fn test_sstring_from_utf16_surrogate_pair() {
+        // U+1F600 GRINNING FACE encoded as the surrogate pair 0xD83D 0xDE00
+        let units: Vec<u16> = vec![0xD83D, 0xDE00];
+        let sstring = SString::from_utf16(&units);
+        assert_eq!(sstring.unchecked_safe(), "\u{1F600}");
+        assert_eq!(sstring.garbage(), Vec::<u8>::new());
+    }
+
+    #[test]
+    pub fn test_sstring_from_utf16_lone_low_surrogate() {
+        // A lone low surrogate (0xDC00) is just as invalid as a lone high one.
+        let units: Vec<u16> = vec![0x0041, 0xDC00, 0x0042];
+        let sstring = SString::from_utf16(&units);
+        assert_eq!(sstring.unchecked_safe(), "AB");
+        assert_eq!(sstring.garbage(), vec![0xDC, 0x00]);
+    }
+
+    #[test]
+    pub fn test_sstring_extend_utf16_bytes() {
+        let mut sstring = SString::empty();
+        sstring.extend_utf16_bytes(&[0x00, 0x41], crate::Endianness::Big);
+        sstring.extend_utf16_bytes(&[0x00, 0x42], crate::Endianness::Big);
+        assert_eq!(sstring.unchecked_safe(), "AB");
+    }
 }
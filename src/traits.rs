@@ -1,8 +1,11 @@
 //! the traits module contains the [`SafeString`].
 use crate::sstring::SString;
-use std::borrow::Cow;
-use std::ffi::OsStr;
-use std::ffi::OsString;
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use std::ffi::{OsStr, OsString};
 
 /// The `SafeString` trait is useful for converting from various string-related types into [`SString`].
 pub trait SafeString: Into<SString> + Clone {
@@ -22,9 +25,12 @@ impl SafeString for SString {
 
 impl SafeString for String {}
 
+#[cfg(feature = "std")]
 impl SafeString for OsString {}
 
 impl<'a> SafeString for &'a str {}
+
+#[cfg(feature = "std")]
 impl<'a> SafeString for &'a OsStr {}
 
 impl SafeString for Cow<'static, str> {}